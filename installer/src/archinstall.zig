@@ -119,6 +119,8 @@ pub fn writeUserConfiguration(alloc: std.mem.Allocator, cfg: InstallConfig) !voi
         \\            "size": {{ "unit": "Percent", "value": 100 }},
         \\            "start": {{ "unit": "MiB", "value": 513 }},
         \\            "mountpoint": null,
+        \\            "// mount_options": "btrfs only: transparent zstd compression on every subvol (what archinstall's own compression prompt sets). Snapshots share the compressed extents. ext4 gets none.",
+        \\            "mount_options": [{s}],
         \\            "btrfs": [
         \\              {{ "name": "@",          "mountpoint": "/" }},
         \\              {{ "name": "@home",      "mountpoint": "/home" }},
@@ -147,6 +149,7 @@ pub fn writeUserConfiguration(alloc: std.mem.Allocator, cfg: InstallConfig) !voi
         cfg.hostname,
         cfg.disk_path,
         if (cfg.btrfs) "btrfs" else "ext4",
+        if (cfg.btrfs) "\"compress=zstd\"" else "",
         if (cfg.encrypt) "yes" else "no",
         pkg,
     });
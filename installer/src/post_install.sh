@@ -13,6 +13,8 @@
 #      initramfs hook so a booted snapshot is a usable read-write system
 #   5. enable sddm.service (belt-and-suspenders: koompi-branding ships a preset
 #      that already enables it — harmless to enable again)
+#   5b. enable the other units that preset lists, the same way
+#       (power-profiles-daemon.service, paccache.timer)
 #   6. write /etc/os-release (NOT shipped by any package — filesystem owns the
 #      stock one; we overwrite with KOOMPI identity)
 #
@@ -159,6 +161,25 @@ enable_login() {
   systemctl enable sddm.service || true
 }
 
+# ─────────────────────────────────────────────────────────────────────────────
+# 5b. The preset's other units. Arch never applies presets on package install,
+#     so each unit koompi-branding's preset lists is enabled here too, like
+#     sddm, and before pin_baseline so the baseline captures it.
+# ─────────────────────────────────────────────────────────────────────────────
+# power-profiles-daemon (koompi-toolkit) backs the shell's Power Profile toggle.
+enable_ppd() {
+  log "enabling power-profiles-daemon.service"
+  systemctl enable power-profiles-daemon.service || true
+}
+
+# paccache.timer (pacman-contrib, koompi-toolkit) weekly keeps the last 3
+# versions of each package. /var/cache is its own subvol (@var_cache), outside
+# every snapshot, so nothing else bounds it.
+enable_cache_prune() {
+  log "enabling paccache.timer"
+  systemctl enable paccache.timer || true
+}
+
 # ─────────────────────────────────────────────────────────────────────────────
 # 6. /etc/os-release — KOOMPI identity. Deliberately NOT a package (the
 #    `filesystem` package owns the stock file); we overwrite in the target.
@@ -188,6 +209,8 @@ main() {
   ensure_pkgs
   setup_snapper       # snapper config + restore archinstall's @snapshots subvol
   enable_login        # enable sddm BEFORE the baseline so it captures it
+  enable_ppd          # power-profiles-daemon, also before the baseline
+  enable_cache_prune  # paccache.timer, also before the baseline
   write_os_release    # bake KOOMPI identity into the baseline too
   setup_snapshot_boot # grub-btrfs-overlayfs initramfs hook (bootable snapshots)
   pin_baseline        # snapshot the FINISHED install (un-prunable factory reset)